@@ -0,0 +1,195 @@
+use std::error;
+use std::fmt;
+
+/// An error returned from [`BiChannel::send`] when every clone of the
+/// peer endpoint has been dropped.
+///
+/// The error contains the message so it can be recovered.
+///
+/// [`BiChannel::send`]: struct.BiChannel.html#method.send
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "SendError(..)".fmt(f)
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "sending on a disconnected channel".fmt(f)
+    }
+}
+
+impl<T> error::Error for SendError<T> {}
+
+impl<T> SendError<T> {
+    /// Unwraps the undelivered message.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// An error returned from [`BiChannel::recv`] when every clone of the
+/// peer endpoint has been dropped and no further messages can arrive.
+///
+/// [`BiChannel::recv`]: struct.BiChannel.html#method.recv
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RecvError;
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "receiving on a disconnected channel".fmt(f)
+    }
+}
+
+impl error::Error for RecvError {}
+
+/// An error returned from [`BiChannel::try_send`].
+///
+/// The error contains the message so it can be recovered.
+///
+/// [`BiChannel::try_send`]: struct.BiChannel.html#method.try_send
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum TrySendError<T> {
+    /// The message could not be sent because the channel is full.
+    ///
+    /// On a zero-capacity channel, this means there was no receiving
+    /// operation on the other side at the same time.
+    Full(T),
+
+    /// The message could not be sent because every clone of the peer
+    /// endpoint has been dropped.
+    Disconnected(T),
+}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TrySendError::Full(..) => "Full(..)".fmt(f),
+            TrySendError::Disconnected(..) => "Disconnected(..)".fmt(f),
+        }
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TrySendError::Full(..) => "sending on a full channel".fmt(f),
+            TrySendError::Disconnected(..) => "sending on a disconnected channel".fmt(f),
+        }
+    }
+}
+
+impl<T> error::Error for TrySendError<T> {}
+
+impl<T> TrySendError<T> {
+    /// Unwraps the undelivered message.
+    pub fn into_inner(self) -> T {
+        match self {
+            TrySendError::Full(msg) => msg,
+            TrySendError::Disconnected(msg) => msg,
+        }
+    }
+}
+
+/// An error returned from [`BiChannel::send_timeout`] and
+/// [`BiChannel::send_deadline`].
+///
+/// The error contains the message so it can be recovered.
+///
+/// [`BiChannel::send_timeout`]: struct.BiChannel.html#method.send_timeout
+/// [`BiChannel::send_deadline`]: struct.BiChannel.html#method.send_deadline
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum SendTimeoutError<T> {
+    /// The message could not be sent before the deadline passed.
+    Timeout(T),
+
+    /// The message could not be sent because every clone of the peer
+    /// endpoint has been dropped.
+    Disconnected(T),
+}
+
+impl<T> fmt::Debug for SendTimeoutError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SendTimeoutError::Timeout(..) => "Timeout(..)".fmt(f),
+            SendTimeoutError::Disconnected(..) => "Disconnected(..)".fmt(f),
+        }
+    }
+}
+
+impl<T> fmt::Display for SendTimeoutError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SendTimeoutError::Timeout(..) => "timed out waiting on send operation".fmt(f),
+            SendTimeoutError::Disconnected(..) => "sending on a disconnected channel".fmt(f),
+        }
+    }
+}
+
+impl<T> error::Error for SendTimeoutError<T> {}
+
+impl<T> SendTimeoutError<T> {
+    /// Unwraps the undelivered message.
+    pub fn into_inner(self) -> T {
+        match self {
+            SendTimeoutError::Timeout(msg) => msg,
+            SendTimeoutError::Disconnected(msg) => msg,
+        }
+    }
+}
+
+/// An error returned from [`BiChannel::try_recv`].
+///
+/// [`BiChannel::try_recv`]: struct.BiChannel.html#method.try_recv
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TryRecvError {
+    /// No message was received because the channel is currently empty.
+    ///
+    /// On a zero-capacity channel, this means there was no sending
+    /// operation on the other side at the same time.
+    Empty,
+
+    /// No message was received because every clone of the peer endpoint
+    /// has been dropped and the channel is drained.
+    Disconnected,
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TryRecvError::Empty => "receiving on an empty channel".fmt(f),
+            TryRecvError::Disconnected => "receiving on an empty and disconnected channel".fmt(f),
+        }
+    }
+}
+
+impl error::Error for TryRecvError {}
+
+/// An error returned from [`BiChannel::recv_timeout`] and
+/// [`BiChannel::recv_deadline`].
+///
+/// [`BiChannel::recv_timeout`]: struct.BiChannel.html#method.recv_timeout
+/// [`BiChannel::recv_deadline`]: struct.BiChannel.html#method.recv_deadline
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RecvTimeoutError {
+    /// No message arrived before the deadline passed.
+    Timeout,
+
+    /// No message was received because every clone of the peer endpoint
+    /// has been dropped and the channel is drained.
+    Disconnected,
+}
+
+impl fmt::Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RecvTimeoutError::Timeout => "timed out waiting on recv operation".fmt(f),
+            RecvTimeoutError::Disconnected => "receiving on an empty and disconnected channel".fmt(f),
+        }
+    }
+}
+
+impl error::Error for RecvTimeoutError {}
@@ -13,12 +13,12 @@
 //! thread::spawn(move || {
 //!     loop {
 //!         let val = right.recv().unwrap();
-//!         right.send(val % 2 == 0);
+//!         right.send(val % 2 == 0).unwrap();
 //!     }
 //! });
 //!
 //! for i in 0..10 {
-//!     left.send(i);
+//!     left.send(i).unwrap();
 //!     if left.recv().unwrap() {
 //!         println!("{} is even", i);
 //!     }
@@ -28,7 +28,29 @@
 extern crate crossbeam_channel as channel;
 use channel::internal::select::{RecvArgument, SendArgument};
 use channel::{Receiver, Sender};
+use std::fmt;
+use std::iter::FusedIterator;
 use std::option;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+
+pub use err::{RecvError, RecvTimeoutError, SendError, SendTimeoutError, TryRecvError, TrySendError};
+
+mod err;
+
+/// How long a blocking [`BiChannel::send`] or [`BiChannel::send_deadline`]
+/// waits between attempts before re-checking whether the peer endpoint has
+/// disconnected.
+///
+/// crossbeam-channel 0.2 gives a `Sender` no way to learn that every
+/// `Receiver` on the other end has been dropped, so disconnection is
+/// detected out-of-band (see the `alive`/`peer_alive` fields on
+/// `BiChannel`) and sending has to poll for it instead of blocking
+/// indefinitely on the underlying channel.
+///
+/// [`BiChannel::send`]: struct.BiChannel.html#method.send
+/// [`BiChannel::send_deadline`]: struct.BiChannel.html#method.send_deadline
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
 
 /// Creates a bi-directional channel of bounded capacity.
 ///
@@ -64,7 +86,7 @@ use std::option;
 ///
 /// thread::spawn(move || {
 ///     // ...
-///     left.send(());
+///     left.send(()).unwrap();
 /// });
 ///
 /// println!("waiting for rendezvous");
@@ -74,7 +96,10 @@ use std::option;
 pub fn bounded<T, U>(cap: usize) -> (BiChannel<T, U>, BiChannel<U, T>) {
     let (tx1, rx1) = channel::bounded(cap);
     let (tx2, rx2) = channel::bounded(cap);
-    (BiChannel::new(tx1, rx2), BiChannel::new(tx2, rx1))
+    let (left_alive, right_alive) = (Arc::new(()), Arc::new(()));
+    let left = BiChannel::new(tx1, rx2, left_alive.clone(), Arc::downgrade(&right_alive));
+    let right = BiChannel::new(tx2, rx1, right_alive, Arc::downgrade(&left_alive));
+    (left, right)
 }
 
 /// Creates a bi-directional channel of unbounded capacity.
@@ -94,36 +119,361 @@ pub fn bounded<T, U>(cap: usize) -> (BiChannel<T, U>, BiChannel<U, T>) {
 /// ```rust
 /// # use doublecross::unbounded;
 /// let (left, right) = unbounded::<i32, i32>();
-/// left.send(10);
-/// assert_eq!(right.recv(), Some(10));
+/// left.send(10).unwrap();
+/// assert_eq!(right.recv(), Ok(10));
 /// ```
 pub fn unbounded<T, U>() -> (BiChannel<T, U>, BiChannel<U, T>) {
     let (tx1, rx1) = channel::unbounded();
     let (tx2, rx2) = channel::unbounded();
-    (BiChannel::new(tx1, rx2), BiChannel::new(tx2, rx1))
+    let (left_alive, right_alive) = (Arc::new(()), Arc::new(()));
+    let left = BiChannel::new(tx1, rx2, left_alive.clone(), Arc::downgrade(&right_alive));
+    let right = BiChannel::new(tx2, rx1, right_alive, Arc::downgrade(&left_alive));
+    (left, right)
 }
 
 /// Bi-directional communication build on, and
 /// usable with, crossbeam-channel channels.
+///
+/// `BiChannel` is [`Clone`], so several threads can share one side of a
+/// channel to build fan-in (several producers cloning the sending side)
+/// or fan-out (several consumers cloning the receiving side) topologies.
+/// Disconnection is only observed once *every* clone of the peer endpoint
+/// has been dropped: cloning bumps the same `alive` marker the clones
+/// were made from, so `peer_disconnected` stays `false` as long as one
+/// clone remains.
+///
+/// `rx`/`tx` are private precisely so that invariant holds: the only way
+/// to get another handle onto either channel half is through
+/// `BiChannel::clone()`, which always clones `alive`/`peer_alive` along
+/// with it. A raw `Receiver`/`Sender` clone obtained some other way would
+/// keep the underlying channel open without the matching marker, making
+/// `peer_disconnected` lie in either direction.
+///
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
 pub struct BiChannel<T, U> {
-    pub rx: Receiver<T>,
-    pub tx: Sender<U>,
+    rx: Receiver<T>,
+    tx: Sender<U>,
+    // Never read directly: its only job is to keep the strong count above
+    // zero so the peer's `peer_alive` can observe this endpoint as alive.
+    #[allow(dead_code)]
+    alive: Arc<()>,
+    peer_alive: Weak<()>,
+}
+
+impl<T, U> Clone for BiChannel<T, U> {
+    /// Clones this endpoint so several threads can share it.
+    ///
+    /// All clones refer to the same underlying channel halves and the
+    /// same `alive` marker, so the peer only observes a disconnect once
+    /// every clone (of this side) has been dropped.
+    fn clone(&self) -> Self {
+        BiChannel {
+            rx: self.rx.clone(),
+            tx: self.tx.clone(),
+            alive: self.alive.clone(),
+            peer_alive: self.peer_alive.clone(),
+        }
+    }
 }
 
 impl<T, U> BiChannel<T, U> {
-    pub fn new(tx: Sender<U>, rx: Receiver<T>) -> Self {
-        BiChannel { rx, tx }
+    /// Builds a `BiChannel` out of its halves together with the
+    /// disconnection markers `bounded`/`unbounded` set up for their pair.
+    ///
+    /// This is kept private because `alive`/`peer_alive` only mean
+    /// anything when each side's marker was made from the same pairing
+    /// call; constructing one on its own would make every `send` see
+    /// the peer as permanently disconnected.
+    fn new(tx: Sender<U>, rx: Receiver<T>, alive: Arc<()>, peer_alive: Weak<()>) -> Self {
+        BiChannel { rx, tx, alive, peer_alive }
+    }
+
+    /// `true` once every clone of the peer endpoint has been dropped.
+    fn peer_disconnected(&self) -> bool {
+        self.peer_alive.upgrade().is_none()
+    }
+
+    /// Sends a message to the other side of the channel, blocking the
+    /// current thread if the channel is full.
+    ///
+    /// Returns `Err` with the message handed back if every clone of the
+    /// peer endpoint has already been dropped, rather than blocking
+    /// forever or silently discarding the message.
+    pub fn send(&self, msg: U) -> Result<(), SendError<U>> {
+        let mut pending = Some(msg);
+        loop {
+            if self.peer_disconnected() {
+                return Err(SendError(pending.take().unwrap()));
+            }
+
+            let msg = pending.take().unwrap();
+            select! {
+                send(self.tx, msg) => return Ok(()),
+                recv(channel::after(POLL_INTERVAL)) => pending = Some(msg),
+            }
+        }
+    }
+
+    /// Sends a message without blocking.
+    ///
+    /// Returns `Err` immediately if the channel is full, or if every clone
+    /// of the peer endpoint has been dropped. On a zero-capacity channel,
+    /// this only succeeds if a matching receive operation is simultaneously
+    /// present on the other side.
+    pub fn try_send(&self, msg: U) -> Result<(), TrySendError<U>> {
+        if self.peer_disconnected() {
+            return Err(TrySendError::Disconnected(msg));
+        }
+
+        select! {
+            send(self.tx, msg) => Ok(()),
+            default => Err(TrySendError::Full(msg)),
+        }
+    }
+
+    /// Sends a message, blocking the current thread for at most `timeout`.
+    ///
+    /// Returns `Err` if the deadline passes before the message can be sent,
+    /// or once every clone of the peer endpoint has been dropped.
+    pub fn send_timeout(&self, msg: U, timeout: Duration) -> Result<(), SendTimeoutError<U>> {
+        self.send_deadline(msg, Instant::now() + timeout)
+    }
+
+    /// Sends a message, blocking the current thread until `deadline`.
+    ///
+    /// Returns `Err` if the deadline passes before the message can be sent,
+    /// or once every clone of the peer endpoint has been dropped.
+    pub fn send_deadline(&self, msg: U, deadline: Instant) -> Result<(), SendTimeoutError<U>> {
+        let mut pending = Some(msg);
+        loop {
+            if self.peer_disconnected() {
+                return Err(SendTimeoutError::Disconnected(pending.take().unwrap()));
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(SendTimeoutError::Timeout(pending.take().unwrap()));
+            }
+
+            let msg = pending.take().unwrap();
+            select! {
+                send(self.tx, msg) => return Ok(()),
+                recv(channel::after(POLL_INTERVAL.min(deadline - now))) => pending = Some(msg),
+            }
+        }
+    }
+
+    /// Blocks the current thread until a message arrives from the other
+    /// side of the channel.
+    ///
+    /// Returns `Err` once the peer endpoint (and every one of its clones)
+    /// has been dropped and no further messages can arrive.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        self.rx.recv().ok_or(RecvError)
+    }
+
+    /// Receives a message without blocking.
+    ///
+    /// Returns `Err` immediately if the channel is currently empty. On a
+    /// zero-capacity channel, this only succeeds if a matching send
+    /// operation is simultaneously present on the other side.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        select! {
+            recv(self.rx, msg) => msg.ok_or(TryRecvError::Disconnected),
+            default => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Blocks the current thread for at most `timeout` waiting for a
+    /// message to arrive from the other side of the channel.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.recv_deadline(Instant::now() + timeout)
+    }
+
+    /// Blocks the current thread until `deadline` waiting for a message to
+    /// arrive from the other side of the channel.
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        let now = Instant::now();
+        if now >= deadline {
+            return select! {
+                recv(self.rx, msg) => msg.ok_or(RecvTimeoutError::Disconnected),
+                default => Err(RecvTimeoutError::Timeout),
+            };
+        }
+
+        select! {
+            recv(self.rx, msg) => msg.ok_or(RecvTimeoutError::Disconnected),
+            recv(channel::after(deadline - now)) => Err(RecvTimeoutError::Timeout),
+        }
     }
 
-    pub fn send(&self, msg: U) {
-        self.tx.send(msg)
+    /// A blocking iterator over messages arriving from the other side of
+    /// the channel.
+    ///
+    /// Each call to `next` blocks until a message arrives; it returns
+    /// `None` once the peer endpoint has been dropped.
+    ///
+    /// `Iter` only borrows the receiving half, so `self` is still free to
+    /// `send` while the iterator is alive, e.g. `for msg in left.iter() {
+    /// left.send(reply(msg)).unwrap(); }`. (`&BiChannel` isn't itself an
+    /// `IntoIterator` — doing so would overlap with the `SendArgument`
+    /// blanket impl that lets `BiChannel` participate in `select!`.)
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { rx: &self.rx }
     }
 
-    pub fn recv(&self) -> Option<T> {
+    /// A non-blocking iterator over messages already buffered from the
+    /// other side of the channel.
+    ///
+    /// Each call to `next` returns immediately, yielding `None` as soon as
+    /// no message is currently available rather than waiting for one.
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter { rx: &self.rx }
+    }
+
+    /// Returns the number of messages currently buffered, waiting to be
+    /// received from the other side of the channel.
+    pub fn rx_len(&self) -> usize {
+        self.rx.len()
+    }
+
+    /// Returns the number of messages this side has sent that are still
+    /// buffered, waiting for the other side to receive them.
+    ///
+    /// A `tx_len()` close to `capacity()` means the next `send` is likely
+    /// to block; checking it before sending lets a caller back off instead
+    /// of risking the two-sided deadlock described in the [`bounded`] docs.
+    ///
+    /// [`bounded`]: fn.bounded.html
+    pub fn tx_len(&self) -> usize {
+        self.tx.len()
+    }
+
+    /// If the channel is bounded, returns its capacity.
+    ///
+    /// `bounded` gives both directions the same capacity, so this single
+    /// value applies to both the `rx_len()` and `tx_len()` buffers.
+    pub fn capacity(&self) -> Option<usize> {
+        self.rx.capacity()
+    }
+
+    /// Returns `true` if there are no messages currently buffered, waiting
+    /// to be received from the other side of the channel.
+    ///
+    /// Note: a zero-capacity (rendezvous) channel is always empty.
+    pub fn rx_is_empty(&self) -> bool {
+        self.rx.is_empty()
+    }
+
+    /// Returns `true` if this side has no messages buffered, waiting for
+    /// the other side to receive them.
+    ///
+    /// Note: a zero-capacity (rendezvous) channel is always empty.
+    pub fn tx_is_empty(&self) -> bool {
+        self.tx.is_empty()
+    }
+
+    /// Returns `true` if the buffer of messages waiting to be received
+    /// from the other side of the channel is full.
+    ///
+    /// Note: a zero-capacity (rendezvous) channel is always full.
+    pub fn rx_is_full(&self) -> bool {
+        self.rx.is_full()
+    }
+
+    /// Returns `true` if this side's buffer of messages waiting to be
+    /// received by the other side is full; a `send` is likely to block.
+    ///
+    /// Note: a zero-capacity (rendezvous) channel is always full.
+    pub fn tx_is_full(&self) -> bool {
+        self.tx.is_full()
+    }
+}
+
+/// A blocking iterator over messages received by a [`BiChannel`].
+///
+/// Created by [`BiChannel::iter`].
+///
+/// [`BiChannel`]: struct.BiChannel.html
+/// [`BiChannel::iter`]: struct.BiChannel.html#method.iter
+pub struct Iter<'a, T: 'a> {
+    rx: &'a Receiver<T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
         self.rx.recv()
     }
 }
 
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("Iter { .. }")
+    }
+}
+
+/// A non-blocking iterator over messages received by a [`BiChannel`].
+///
+/// Created by [`BiChannel::try_iter`].
+///
+/// [`BiChannel`]: struct.BiChannel.html
+/// [`BiChannel::try_iter`]: struct.BiChannel.html#method.try_iter
+pub struct TryIter<'a, T: 'a> {
+    rx: &'a Receiver<T>,
+}
+
+impl<'a, T> Iterator for TryIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rx.try_recv()
+    }
+}
+
+impl<'a, T> fmt::Debug for TryIter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("TryIter { .. }")
+    }
+}
+
+/// A blocking iterator over messages received by a consumed [`BiChannel`].
+///
+/// Created by `BiChannel`'s `IntoIterator` impl.
+///
+/// [`BiChannel`]: struct.BiChannel.html
+pub struct IntoIter<T> {
+    rx: Receiver<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rx.recv()
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("IntoIter { .. }")
+    }
+}
+
+impl<T, U> IntoIterator for BiChannel<T, U> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { rx: self.rx }
+    }
+}
+
 impl<'a, T, U> RecvArgument<'a, T> for &'a BiChannel<T, U> {
     type Iter = option::IntoIter<&'a Receiver<T>>;
 
@@ -149,10 +499,10 @@ mod tests {
     #[test]
     fn simultaneous_handover() {
         let (left, right) = super::bounded(1);
-        left.send(10);
-        right.send(20);
-        assert_eq!(left.recv(), Some(20));
-        assert_eq!(right.recv(), Some(10));
+        left.send(10).unwrap();
+        right.send(20).unwrap();
+        assert_eq!(left.recv(), Ok(20));
+        assert_eq!(right.recv(), Ok(10));
     }
 
     #[test]
@@ -161,7 +511,7 @@ mod tests {
         let timeout = Duration::from_millis(10);
 
         thread::spawn(move || {
-            left.send(());
+            left.send(()).unwrap();
         });
 
         select! {
@@ -178,7 +528,7 @@ mod tests {
         let timeout = Duration::from_millis(10);
 
         thread::spawn(move || {
-            left.recv();
+            left.recv().unwrap();
         });
 
         select! {
@@ -192,9 +542,235 @@ mod tests {
     #[test]
     fn asymmetric_message_types() {
         let (left, right) = super::unbounded::<u8, i16>();
-        left.send(0i16);
+        left.send(0i16).unwrap();
         assert_eq!(right.recv().unwrap(), 0i16);
-        right.send(0u8);
+        right.send(0u8).unwrap();
         assert_eq!(left.recv().unwrap(), 0u8);
     }
+
+    #[test]
+    fn send_fails_once_peer_is_dropped() {
+        let (left, right) = super::unbounded::<(), i32>();
+        drop(right);
+        assert_eq!(left.send(42), Err(super::SendError(42)));
+    }
+
+    #[test]
+    fn recv_fails_once_peer_is_dropped() {
+        let (left, right) = super::unbounded::<i32, ()>();
+        drop(right);
+        assert_eq!(left.recv(), Err(super::RecvError));
+    }
+
+    #[test]
+    fn send_blocks_until_a_receiver_drains_a_full_buffer() {
+        let (left, right) = super::bounded::<(), i32>(1);
+        left.send(1).unwrap();
+
+        let sender = thread::spawn(move || left.send(2).unwrap());
+
+        // give the background send a chance to start blocking on the
+        // full buffer before we drain it
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(right.recv(), Ok(1));
+        sender.join().unwrap();
+        assert_eq!(right.recv(), Ok(2));
+    }
+
+    #[test]
+    fn try_recv_reports_empty_without_blocking() {
+        let (left, _right) = super::unbounded::<i32, ()>();
+        assert_eq!(left.try_recv(), Err(super::TryRecvError::Empty));
+    }
+
+    #[test]
+    fn try_recv_reports_disconnected() {
+        let (left, right) = super::unbounded::<i32, ()>();
+        drop(right);
+        assert_eq!(left.try_recv(), Err(super::TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn try_send_reports_full_rendezvous() {
+        let (left, _right) = super::bounded::<(), ()>(0);
+        assert_eq!(left.try_send(()), Err(super::TrySendError::Full(())));
+    }
+
+    #[test]
+    fn try_send_reports_disconnected() {
+        let (left, right) = super::unbounded::<(), i32>();
+        drop(right);
+        assert_eq!(left.try_send(42), Err(super::TrySendError::Disconnected(42)));
+    }
+
+    #[test]
+    fn recv_timeout_reports_timeout_on_an_empty_channel() {
+        let (left, _right) = super::unbounded::<i32, ()>();
+        assert_eq!(
+            left.recv_timeout(Duration::from_millis(10)),
+            Err(super::RecvTimeoutError::Timeout)
+        );
+    }
+
+    #[test]
+    fn recv_timeout_succeeds_before_the_deadline() {
+        let (left, right) = super::unbounded::<i32, ()>();
+        thread::spawn(move || right.send(7).unwrap());
+        assert_eq!(left.recv_timeout(Duration::from_millis(100)), Ok(7));
+    }
+
+    #[test]
+    fn send_timeout_reports_timeout_on_a_full_rendezvous() {
+        let (left, _right) = super::bounded::<(), ()>(0);
+        assert_eq!(
+            left.send_timeout((), Duration::from_millis(10)),
+            Err(super::SendTimeoutError::Timeout(()))
+        );
+    }
+
+    #[test]
+    fn iter_stops_once_the_peer_is_dropped() {
+        let (left, right) = super::unbounded::<i32, ()>();
+        thread::spawn(move || {
+            right.send(1).unwrap();
+            right.send(2).unwrap();
+            // dropping right closes the channel once both sends land
+        });
+
+        let received: Vec<_> = left.iter().collect();
+        assert_eq!(received, vec![1, 2]);
+    }
+
+    #[test]
+    fn try_iter_only_drains_whats_already_buffered() {
+        let (left, right) = super::unbounded::<i32, ()>();
+        right.send(1).unwrap();
+        right.send(2).unwrap();
+
+        let buffered: Vec<_> = left.try_iter().collect();
+        assert_eq!(buffered, vec![1, 2]);
+        assert_eq!(left.try_recv(), Err(super::TryRecvError::Empty));
+    }
+
+    #[test]
+    fn into_iter_consumes_the_channel() {
+        let (left, right) = super::unbounded::<i32, ()>();
+        right.send(1).unwrap();
+        right.send(2).unwrap();
+        drop(right);
+
+        let received: Vec<_> = left.into_iter().collect();
+        assert_eq!(received, vec![1, 2]);
+    }
+
+    #[test]
+    fn fan_in_from_several_cloned_senders() {
+        let (left, right) = super::unbounded::<i32, i32>();
+
+        for n in 0..3 {
+            let right = right.clone();
+            thread::spawn(move || {
+                right.send(n).unwrap();
+            });
+        }
+        drop(right);
+
+        let mut received: Vec<_> = left.iter().take(3).collect();
+        received.sort();
+        assert_eq!(received, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn fan_out_to_several_cloned_receivers() {
+        let (left, right) = super::unbounded::<(), i32>();
+        let workers: Vec<_> = (0..3)
+            .map(|_| {
+                let right = right.clone();
+                thread::spawn(move || right.recv().unwrap())
+            })
+            .collect();
+        drop(right);
+
+        for n in 0..3 {
+            left.send(n).unwrap();
+        }
+
+        let mut received: Vec<_> = workers.into_iter().map(|w| w.join().unwrap()).collect();
+        received.sort();
+        assert_eq!(received, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn disconnect_is_observed_only_once_every_clone_is_dropped() {
+        let (left, right) = super::unbounded::<(), ()>();
+        let right_clone = right.clone();
+
+        drop(right);
+        // a clone is still alive, so the peer isn't disconnected yet
+        assert_eq!(left.try_send(()), Ok(()));
+        assert_eq!(left.try_recv(), Err(super::TryRecvError::Empty));
+
+        drop(right_clone);
+        assert_eq!(left.send(()), Err(super::SendError(())));
+    }
+
+    #[test]
+    fn disconnect_is_observed_only_once_a_clone_of_a_clone_is_dropped() {
+        let (left, right) = super::unbounded::<(), ()>();
+        let right_clone = right.clone();
+        let right_clone_of_clone = right_clone.clone();
+
+        drop(right);
+        drop(right_clone);
+        // a clone two generations removed from the original is still
+        // alive, so the peer isn't disconnected yet
+        assert_eq!(left.try_send(()), Ok(()));
+
+        drop(right_clone_of_clone);
+        assert_eq!(left.send(()), Err(super::SendError(())));
+    }
+
+    #[test]
+    fn introspection_reports_buffered_messages() {
+        let (left, right) = super::bounded::<i32, ()>(2);
+        assert_eq!(left.rx_len(), 0);
+        assert_eq!(left.capacity(), Some(2));
+        assert!(left.rx_is_empty());
+        assert!(!left.rx_is_full());
+
+        right.send(1).unwrap();
+        right.send(2).unwrap();
+        assert_eq!(left.rx_len(), 2);
+        assert!(!left.rx_is_empty());
+        assert!(left.rx_is_full());
+    }
+
+    #[test]
+    fn introspection_reports_the_senders_own_buffered_messages() {
+        let (left, right) = super::bounded::<(), i32>(2);
+        assert_eq!(left.tx_len(), 0);
+        assert!(left.tx_is_empty());
+        assert!(!left.tx_is_full());
+
+        left.send(1).unwrap();
+        left.send(2).unwrap();
+        assert_eq!(left.tx_len(), 2);
+        assert!(!left.tx_is_empty());
+        assert!(left.tx_is_full());
+
+        assert_eq!(right.recv(), Ok(1));
+        assert_eq!(left.tx_len(), 1);
+    }
+
+    #[test]
+    fn a_rendezvous_channel_is_always_simultaneously_empty_and_full() {
+        let (left, _right) = super::bounded::<i32, ()>(0);
+        assert_eq!(left.rx_len(), 0);
+        assert_eq!(left.capacity(), Some(0));
+        assert!(left.rx_is_empty());
+        assert!(left.rx_is_full());
+        assert_eq!(left.tx_len(), 0);
+        assert!(left.tx_is_empty());
+        assert!(left.tx_is_full());
+    }
 }